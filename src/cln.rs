@@ -0,0 +1,19 @@
+use num_traits::Float;
+use num_complex::Complex;
+
+/// Complex natural logarithm that maps a signed zero in either component to
+/// `+0`, so the principal branch is taken consistently on the negative real
+/// axis regardless of how the argument was constructed.
+pub(crate) trait CLn<T> {
+    fn cln(&self) -> T;
+}
+
+impl<T: Float> CLn<Complex<T>> for Complex<T> {
+    fn cln(&self) -> Complex<T> {
+        let z = Complex::new(
+            if self.re == T::zero() { T::zero() } else { self.re },
+            if self.im == T::zero() { T::zero() } else { self.im },
+        );
+        Complex::new(T::from(0.5).unwrap()*z.norm_sqr().ln(), z.arg())
+    }
+}
@@ -0,0 +1,190 @@
+use num_traits::Float;
+use num_complex::Complex;
+use li2::{from_f64_complex, fma};
+use cln::CLn;
+use Scalar;
+
+/// Provides the third order polylogarithm function `li3()` of a number of
+/// type `T`.
+pub trait Li3<T> {
+    fn li3(&self) -> T;
+}
+
+impl<T: Scalar> Li3<Complex<T>> for Complex<T> {
+    /// Returns the third order polylogarithm of a complex number of type
+    /// `Complex<T>`.
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate num_complex;
+    /// extern crate polylog;
+    /// use num_complex::Complex;
+    /// use polylog::Li3;
+    ///
+    /// fn main() {
+    ///     let z = Complex::new(1.0, 1.0);
+    ///     println!("Li3({}) = {}", z, z.li3());
+    /// }
+    /// ```
+    fn li3(&self) -> Complex<T> {
+        let pi    = T::from(core::f64::consts::PI).unwrap();
+        let pi2   = pi*pi;
+        let zeta3 = T::from(1.2020569031595942).unwrap();
+        // a[k] = zeta(3 - k)/k! (the k = 2 entry is the log term, added below)
+        let a: [Complex<T>; 18] = from_f64_complex([
+            1.2020569031595942, 1.6449340668482264, 0.0,
+            -0.08333333333333333, -0.003472222222222222, 0.0,
+            1.1574074074074073e-05, 0.0, -9.841899722852104e-08, 0.0,
+            1.1482216343327454e-09, 0.0, -1.5815724990809165e-11, 0.0,
+            2.4195009792525154e-13, 0.0, -3.982897776989488e-15, 0.0,
+        ]);
+        let bf: [Complex<T>; 18] = from_f64_complex([
+            1.0, -0.375, 0.0787037037037037, -0.008680555555555556,
+            1.2962962962962963e-04, 8.101851851851852e-05,
+            -3.4193571608537595e-06, -1.328656462585034e-06,
+            8.660871756109851e-08, 2.52608759553204e-08,
+            -2.144694468364065e-09, -5.140110622012979e-10,
+            5.24958211460083e-11, 1.0887754406636318e-11,
+            -1.2779396094493695e-12, -2.369824177308745e-13,
+            3.104357887965462e-14, 5.261758629912506e-15,
+        ]);
+
+        let zero = T::zero();
+        let one  = T::one();
+        let cone = Complex::new(one, zero);
+
+        if self.im == zero {
+            if self.re == zero {
+                return Complex::new(zero, zero);
+            }
+            if self.re == one {
+                return Complex::new(zeta3, zero);
+            }
+            if self.re == -one {
+                return Complex::new(-T::from(0.75).unwrap()*zeta3, zero);
+            }
+        }
+
+        let nz  = self.norm_sqr();
+        let pz  = self.arg();
+        let lnz = T::from(0.5).unwrap()*nz.ln();
+
+        if lnz*lnz + pz*pz < one { // |log(z)| < 1
+            let u  = Complex::new(lnz, pz); // log(z)
+            let u2 = u*u;
+
+            let mut acc = a[17];
+            for c in a[..17].iter().rev() {
+                acc = fma(u, acc, *c);
+            }
+            // the k = 2 term u²(H₂ - log(-u))/2!, with H₂ = 3/2
+            let log_term = u2 * (Complex::new(T::from(1.5).unwrap(), zero) - (-u).cln())
+                .unscale(T::from(2).unwrap());
+            return acc + log_term;
+        }
+
+        let (u, rest, sgn) = if nz <= one {
+            (-(cone - self).cln(), Complex::new(zero, zero), one)
+        } else { // nz > 1.0
+            let lmz  = (-self).cln(); // log(-z)
+            let lmz2 = lmz*lmz;
+            // Li₃(z) - Li₃(1/z) = -log³(-z)/6 - π² log(-z)/6
+            let rest = (lmz * (Complex::new(-pi2, zero) - lmz2))
+                .unscale(T::from(6).unwrap());
+            (-(cone - cone/self).cln(), rest, one)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + (u * acc).scale(sgn)
+    }
+}
+
+impl Li3<f64> for f64 {
+    /// Returns the real third order polylogarithm of a real number of type
+    /// `f64`.
+    ///
+    /// Like the real `Li4` path, this evaluates `Li3` in pure real
+    /// arithmetic, sparing callers with a real argument the detour through
+    /// `Complex<f64>` and the spurious imaginary noise it leaks for `z < 1`;
+    /// the branch-cut imaginary contribution on `[1, ∞)` is left to the
+    /// explicit `Complex<f64>` implementation.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::Li3;
+    ///
+    /// let z = 1.0;
+    /// println!("Li3({}) = {}", z, z.li3());
+    /// ```
+    fn li3(&self) -> f64 {
+        let zeta3 = 1.2020569031595942_f64;
+        let x = *self;
+
+        // special cases
+        if x == 0.0 {
+            return 0.0;
+        }
+        if x == 1.0 {
+            return zeta3;
+        }
+        if x == -1.0 {
+            return -0.75*zeta3;
+        }
+
+        let lnx = Float::ln(Float::abs(x)); // = ln|x|, the real part of log(x)
+
+        if x > 0.0 && lnx*lnx < 1.0 {
+            let u  = lnx; // = ln(x)
+            let u2 = u*u;
+            let a: [f64; 18] = [
+                1.2020569031595942, 1.6449340668482264, 0.0,
+                -0.08333333333333333, -0.003472222222222222, 0.0,
+                1.1574074074074073e-05, 0.0, -9.841899722852104e-08, 0.0,
+                1.1482216343327454e-09, 0.0, -1.5815724990809165e-11, 0.0,
+                2.4195009792525154e-13, 0.0, -3.982897776989488e-15, 0.0,
+            ];
+
+            let mut acc = a[17];
+            for c in a[..17].iter().rev() {
+                acc = fma(u, acc, *c);
+            }
+            // the k = 2 term u²(H₂ - log|u|)/2!, with H₂ = 3/2
+            let log_term = u2*(1.5 - Float::ln(Float::abs(u)))/2.0;
+            return acc + log_term;
+        }
+
+        let bf: [f64; 18] = [
+            1.0, -0.375, 0.0787037037037037, -0.008680555555555556,
+            1.2962962962962963e-04, 8.101851851851852e-05,
+            -3.4193571608537595e-06, -1.328656462585034e-06,
+            8.660871756109851e-08, 2.52608759553204e-08,
+            -2.144694468364065e-09, -5.140110622012979e-10,
+            5.24958211460083e-11, 1.0887754406636318e-11,
+            -1.2779396094493695e-12, -2.369824177308745e-13,
+            3.104357887965462e-14, 5.261758629912506e-15,
+        ];
+
+        let (u, rest, sgn) = if Float::abs(x) <= 1.0 {
+            (-Float::ln(1.0 - x), 0.0, 1.0)
+        } else { // |x| > 1
+            let pi  = core::f64::consts::PI;
+            let pi2 = pi*pi;
+            let l   = lnx;
+            let rest = if x > 1.0 {
+                l*(-l*l + 2.0*pi2)/6.0
+            } else { // x < -1, log(-x) is real
+                -(l*l*l + pi2*l)/6.0
+            };
+            (-Float::ln(1.0 - 1.0/x), rest, 1.0)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + sgn*(u*acc)
+    }
+}
@@ -1,20 +1,34 @@
-use num::complex::Complex;
+use num_traits::Float;
+use num_complex::Complex;
+use li2::{from_f64_complex, fma};
+use cln::CLn;
+use BranchSide;
+use Scalar;
 
 /// Provides the fourth order polylogarithm function `li4()` of a
 /// number of type `T`.
 pub trait Li4<T> {
     fn li4(&self) -> T;
+
+    /// Returns `li4()` with an explicit choice of branch-cut side on
+    /// `[1, ∞)`; see [`BranchSide`]. For real-valued types the result
+    /// carries no imaginary part, so the side is immaterial and this
+    /// defaults to [`Li4::li4`].
+    fn li4_branch(&self, side: BranchSide) -> T {
+        let _ = side;
+        self.li4()
+    }
 }
 
-impl Li4<Complex<f64>> for Complex<f64> {
+impl<T: Scalar> Li4<Complex<T>> for Complex<T> {
     /// Returns the fourth order polylogarithm of a complex number of type
-    /// `Complex<f64>`.
+    /// `Complex<T>`.
     ///
     /// # Example:
     /// ```
-    /// extern crate num;
+    /// extern crate num_complex;
     /// extern crate polylog;
-    /// use num::complex::Complex;
+    /// use num_complex::Complex;
     /// use polylog::Li4;
     ///
     /// fn main() {
@@ -22,11 +36,11 @@ impl Li4<Complex<f64>> for Complex<f64> {
     ///     println!("Li4({}) = {}", z, z.li4());
     /// }
     /// ```
-    fn li4(&self) -> Complex<f64> {
-        let pi  = 3.141592653589793;
+    fn li4(&self) -> Complex<T> {
+        let pi  = T::from(core::f64::consts::PI).unwrap();
         let pi2 = pi*pi;
-        let z4  = 1.082323233711138;
-        let bf  = [
+        let z4  = T::from(1.082323233711138).unwrap();
+        let bf: [Complex<T>; 18] = from_f64_complex([
             1., -7./16.,
             1.165123456790123e-01, -1.982060185185185e-02,
             1.927932098765432e-03, -3.105709876543209e-05,
@@ -35,97 +49,204 @@ impl Li4<Complex<f64>> for Complex<f64> {
            -3.882824879172015e-09,  5.446292103220332e-10,
             6.960805210682725e-11, -1.337573768644521e-11,
            -1.278485268526657e-12,  3.260562858024892e-13,
-            2.364757116861825e-14, -7.923135122031161e-15,
-        ];
+            2.364757116861825e-14, -7.92313512203116e-15,
+        ]);
 
-        if self.im == 0.0 {
-            if self.re == 0.0 {
-                return Complex::new(0., 0.);
+        let zero = T::zero();
+        let one  = T::one();
+        let cone = Complex::new(one, zero);
+
+        if self.im == zero {
+            if self.re == zero {
+                return Complex::new(zero, zero);
             }
-            if self.re == 1.0 {
-                return Complex::new(z4, 0.);
+            if self.re == one {
+                return Complex::new(z4, zero);
             }
-            if self.re == -1.0 {
-                return Complex::new(-7./8.*z4, 0.);
+            if self.re == -one {
+                return Complex::new(-T::from(7./8.).unwrap()*z4, zero);
             }
         }
 
         let nz  = self.norm_sqr();
         let pz  = self.arg();
-        let lnz = 0.5*nz.ln();
+        let lnz = T::from(0.5).unwrap()*nz.ln();
 
-        if lnz*lnz + pz*pz < 1. { // |log(z)| < 1
+        if lnz*lnz + pz*pz < one { // |log(z)| < 1
             let u  = Complex::new(lnz, pz);
             let u2 = u*u;
-            let c1 = 1.202056903159594; // zeta(3)
-            let c2 = 0.8224670334241132;
-            let c3 = (11.0/6.0 - (-u).cln())/6.0;
-            let c4 = -1.0/48.0;
+            let c1 = Complex::new(T::from(1.202056903159594).unwrap(), zero); // zeta(3)
+            let c2 = Complex::new(T::from(0.8224670334241132).unwrap(), zero);
+            let c3 = (Complex::new(T::from(11.0/6.0).unwrap(), zero) - (-u).cln())
+                .unscale(T::from(6).unwrap());
+            let c4 = Complex::new(T::from(-1.0/48.0).unwrap(), zero);
 
-            let cs = [
+            let cs: [Complex<T>; 7] = from_f64_complex([
                 -6.944444444444444e-04, 1.653439153439153e-06,
                 -1.093544413650234e-08, 1.043837849393405e-10,
-                -1.216594230062244e-12, 1.613000652835010e-14,
+                -1.216594230062244e-12, 1.61300065283501e-14,
                 -2.342881045287934e-16
-            ];
+            ]);
 
-            return z4 + u2 * (c2 + u2 * c4) +
-                u * (
-                    c1 +
-                    u2 * (c3 +
-                    u2 * (cs[0] +
-                    u2 * (cs[1] +
-                    u2 * (cs[2] +
-                    u2 * (cs[3] +
-                    u2 * (cs[4] +
-                    u2 * (cs[5] +
-                    u2 * (cs[6]))))))))
-                );
+            let mut acc = cs[6];
+            acc = fma(u2, acc, cs[5]);
+            acc = fma(u2, acc, cs[4]);
+            acc = fma(u2, acc, cs[3]);
+            acc = fma(u2, acc, cs[2]);
+            acc = fma(u2, acc, cs[1]);
+            acc = fma(u2, acc, cs[0]);
+            acc = fma(u2, acc, c3);
+            acc = fma(u2, acc, c1);
+            let even = fma(u2, c4, c2); // c2 + u2*c4
+            return Complex::new(z4, zero) + u2 * even + u * acc;
         }
 
-        let (u, rest, sgn) = if nz <= 1.0 {
-            (-(1. - self).cln(), Complex::new(0.,0.), 1.)
+        let (u, rest, sgn) = if nz <= one {
+            (-(cone - self).cln(), Complex::new(zero,zero), one)
         } else { // nz > 1.0
             let pi4  = pi2*pi2;
-            let arg = if pz > 0.0 { pz - pi } else { pz + pi };
+            let arg = if pz > zero { pz - pi } else { pz + pi };
             let lmz = Complex::new(lnz, arg); // (-self).cln()
             let lmz2 = lmz*lmz;
-            (-(1. - 1./self).cln(), 1./360.*(-7.*pi4 + lmz2*(-30.*pi2 - 15.*lmz2)), -1.)
+            let inner = Complex::new(-T::from(30).unwrap()*pi2, zero)
+                - lmz2.scale(T::from(15).unwrap());
+            let rest = (Complex::new(-T::from(7).unwrap()*pi4, zero) + lmz2*inner)
+                .unscale(T::from(360).unwrap());
+            (-(cone - cone/self).cln(), rest, -one)
         };
 
-        rest + sgn * (
-            u * (bf[0] +
-            u * (bf[1] +
-            u * (bf[2] +
-            u * (bf[3] +
-            u * (bf[4] +
-            u * (bf[5] +
-            u * (bf[6] +
-            u * (bf[7] +
-            u * (bf[8] +
-            u * (bf[9] +
-            u * (bf[10] +
-            u * (bf[11] +
-            u * (bf[12] +
-            u * (bf[13] +
-            u * (bf[14] +
-            u * (bf[15] +
-            u * (bf[16] +
-            u * (bf[17]))))))))))))))))))
-        )
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + (u * acc).scale(sgn)
+    }
+
+    /// Returns the complex fourth order polylogarithm with the branch cut
+    /// on `[1, ∞)` taken from the requested `side`. Off the cut the
+    /// function is analytic and `side` has no effect; on the cut
+    /// (`Im z == 0`, `Re z > 1`) the two sheets are complex conjugates and
+    /// the imaginary part is fixed to `±π ln(z)³/6` per [`BranchSide`].
+    fn li4_branch(&self, side: BranchSide) -> Complex<T> {
+        let v = self.li4();
+        if self.im == T::zero() && self.re > T::one() {
+            let pi = T::from(core::f64::consts::PI).unwrap();
+            let l = self.re.ln();
+            let im = pi * l * l * l / T::from(6).unwrap();
+            match side {
+                BranchSide::Above => Complex::new(v.re,  im),
+                BranchSide::Below => Complex::new(v.re, -im),
+            }
+        } else {
+            v
+        }
     }
 }
 
-trait CLn<T> {
-    fn cln(&self) -> T;
-}
+impl Li4<f64> for f64 {
+    /// Returns the real fourth order polylogarithm of a real number of
+    /// type `f64`.
+    ///
+    /// This is the clean, real-valued counterpart to the `Complex<f64>`
+    /// implementation, mirroring how `Li2` already offers a direct real
+    /// path. Callers who know their argument is real avoid both the detour
+    /// through `Complex<f64>` and the spurious imaginary noise it leaks for
+    /// `z < 1`: the polylogarithm is evaluated in pure real arithmetic and
+    /// the branch-cut imaginary contribution on `[1, ∞)` is left to the
+    /// explicit `Complex<f64>` implementation.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::Li4;
+    ///
+    /// let z = 1.0;
+    /// println!("Li4({}) = {}", z, z.li4());
+    /// ```
+    fn li4(&self) -> f64 {
+        let z4 = 1.082323233711138_f64;
+        let x  = *self;
+
+        // special cases
+        if x == 0.0 {
+            return 0.0;
+        }
+        if x == 1.0 {
+            return z4;
+        }
+        if x == -1.0 {
+            return -7.0/8.0*z4;
+        }
+
+        let lnx = Float::ln(Float::abs(x)); // = ln|x|, the real part of log(x)
 
-impl CLn<Complex<f64>> for Complex<f64> {
-    fn cln(&self) -> Complex<f64> {
-        let z = Complex::new(
-            if self.re == 0. { 0. } else { self.re },
-            if self.im == 0. { 0. } else { self.im },
-        );
-        Complex::new(0.5*z.norm_sqr().ln(), z.arg())
+        // |log(z)| < 1 series; real only for x > 0, since x < 0 contributes
+        // pz = pi and |log z|² ≥ pi² > 1.
+        if x > 0.0 && lnx*lnx < 1.0 {
+            let u  = lnx; // = ln(x)
+            let u2 = u*u;
+            let c1 = 1.202056903159594_f64;             // zeta(3)
+            let c2 = 0.8224670334241132_f64;
+            // Re[(11/6 - log(-u))/6]; log(-u) is real for x < 1 and picks up
+            // i*pi for x > 1, whose imaginary part does not reach the result.
+            let c3 = (11.0/6.0 - Float::ln(Float::abs(u)))/6.0;
+            let c4 = -1.0/48.0_f64;
+
+            let cs: [f64; 7] = [
+                -6.944444444444444e-04, 1.653439153439153e-06,
+                -1.093544413650234e-08, 1.043837849393405e-10,
+                -1.216594230062244e-12, 1.61300065283501e-14,
+                -2.342881045287934e-16,
+            ];
+
+            let mut acc = cs[6];
+            acc = fma(u2, acc, cs[5]);
+            acc = fma(u2, acc, cs[4]);
+            acc = fma(u2, acc, cs[3]);
+            acc = fma(u2, acc, cs[2]);
+            acc = fma(u2, acc, cs[1]);
+            acc = fma(u2, acc, cs[0]);
+            acc = fma(u2, acc, c3);
+            acc = fma(u2, acc, c1);
+            let even = fma(u2, c4, c2); // c2 + u2*c4
+            return z4 + u2*even + u*acc;
+        }
+
+        let bf: [f64; 18] = [
+            1., -7./16.,
+            1.165123456790123e-01, -1.982060185185185e-02,
+            1.927932098765432e-03, -3.105709876543209e-05,
+           -1.562400911485783e-05,  8.485123546773206e-07,
+            2.290961660318971e-07, -2.183261421852691e-08,
+           -3.882824879172015e-09,  5.446292103220332e-10,
+            6.960805210682725e-11, -1.337573768644521e-11,
+           -1.278485268526657e-12,  3.260562858024892e-13,
+            2.364757116861825e-14, -7.92313512203116e-15,
+        ];
+
+        let (u, rest, sgn) = if Float::abs(x) <= 1.0 {
+            (-Float::ln(1.0 - x), 0.0, 1.0)
+        } else { // |x| > 1
+            // `rest` is the real part of the complex reflection term; for
+            // x > 1 the log(-x) = ln(x) + i*pi carries an imaginary part that
+            // is folded in analytically here.
+            let pi  = core::f64::consts::PI;
+            let pi2 = pi*pi;
+            let pi4 = pi2*pi2;
+            let l2  = lnx*lnx;
+            let rest = if x > 1.0 {
+                (8.0*pi4 - 15.0*l2*l2 + 60.0*pi2*l2)/360.0
+            } else { // x < -1, log(-x) is real
+                let inner = -30.0*pi2 - 15.0*l2;
+                (-7.0*pi4 + l2*inner)/360.0
+            };
+            (-Float::ln(1.0 - 1.0/x), rest, -1.0)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + sgn*(u*acc)
     }
 }
+
@@ -0,0 +1,197 @@
+use num_traits::Float;
+use num_complex::Complex;
+use li2::{from_f64_complex, fma};
+use cln::CLn;
+use Scalar;
+
+/// Provides the fifth order polylogarithm function `li5()` of a number of
+/// type `T`.
+pub trait Li5<T> {
+    fn li5(&self) -> T;
+}
+
+impl<T: Scalar> Li5<Complex<T>> for Complex<T> {
+    /// Returns the fifth order polylogarithm of a complex number of type
+    /// `Complex<T>`.
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate num_complex;
+    /// extern crate polylog;
+    /// use num_complex::Complex;
+    /// use polylog::Li5;
+    ///
+    /// fn main() {
+    ///     let z = Complex::new(1.0, 1.0);
+    ///     println!("Li5({}) = {}", z, z.li5());
+    /// }
+    /// ```
+    fn li5(&self) -> Complex<T> {
+        let pi    = T::from(core::f64::consts::PI).unwrap();
+        let pi2   = pi*pi;
+        let zeta5 = T::from(1.03692775514337).unwrap();
+        // a[k] = zeta(5 - k)/k! (the k = 4 entry is the log term, added below)
+        let a: [Complex<T>; 18] = from_f64_complex([
+            1.03692775514337, 1.0823232337111381, 0.6010284515797971,
+            0.27415567780803773, 0.0, -0.004166666666666667,
+            -0.00011574074074074075, 0.0, 2.066798941798942e-07, 0.0,
+            -1.0935444136502338e-09, 0.0, 8.698648744945041e-12, 0.0,
+            -8.689958786158883e-14, 0.0, 1.0081254080218813e-15, 0.0,
+        ]);
+        let bf: [Complex<T>; 18] = from_f64_complex([
+            1.0, -0.46875, 0.13953189300411523, -0.02863377700617284,
+            0.004031741255144033, -0.0003398501800411523,
+            4.544518462161767e-06, 2.391680804856901e-06,
+            -1.2762692600122746e-07, -3.162898430650593e-08,
+            3.284811844533519e-09, 4.761371399566057e-10,
+            -8.084689817190984e-11, -7.238764858773721e-12,
+            1.9439760115173968e-12, 1.0256978405977236e-13,
+            -4.618055100988483e-14, -1.153585719647058e-15,
+        ]);
+
+        let zero = T::zero();
+        let one  = T::one();
+        let cone = Complex::new(one, zero);
+
+        if self.im == zero {
+            if self.re == zero {
+                return Complex::new(zero, zero);
+            }
+            if self.re == one {
+                return Complex::new(zeta5, zero);
+            }
+            if self.re == -one {
+                return Complex::new(-T::from(0.9375).unwrap()*zeta5, zero);
+            }
+        }
+
+        let nz  = self.norm_sqr();
+        let pz  = self.arg();
+        let lnz = T::from(0.5).unwrap()*nz.ln();
+
+        if lnz*lnz + pz*pz < one { // |log(z)| < 1
+            let u  = Complex::new(lnz, pz); // log(z)
+            let u4 = u*u*u*u;
+
+            let mut acc = a[17];
+            for c in a[..17].iter().rev() {
+                acc = fma(u, acc, *c);
+            }
+            // the k = 4 term u⁴(H₄ - log(-u))/4!, with H₄ = 25/12
+            let log_term = u4 * (Complex::new(T::from(25.0/12.0).unwrap(), zero) - (-u).cln())
+                .unscale(T::from(24).unwrap());
+            return acc + log_term;
+        }
+
+        let (u, rest, sgn) = if nz <= one {
+            (-(cone - self).cln(), Complex::new(zero, zero), one)
+        } else { // nz > 1.0
+            let lmz  = (-self).cln(); // log(-z)
+            let lmz2 = lmz*lmz;
+            // Li₅(z) - Li₅(1/z) = -log⁵(-z)/120 - π² log³(-z)/36
+            //                     - 7 π⁴ log(-z)/360
+            let pi4 = pi2*pi2;
+            let inner = Complex::new(-pi2, zero).unscale(T::from(36).unwrap())
+                - lmz2.unscale(T::from(120).unwrap());
+            let p = Complex::new(-T::from(7).unwrap()*pi4, zero).unscale(T::from(360).unwrap())
+                + lmz2*inner;
+            let rest = lmz*p;
+            (-(cone - cone/self).cln(), rest, one)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + (u * acc).scale(sgn)
+    }
+}
+
+impl Li5<f64> for f64 {
+    /// Returns the real fifth order polylogarithm of a real number of type
+    /// `f64`.
+    ///
+    /// Like the real `Li4` path, this evaluates `Li5` in pure real
+    /// arithmetic, sparing callers with a real argument the detour through
+    /// `Complex<f64>` and the spurious imaginary noise it leaks for `z < 1`;
+    /// the branch-cut imaginary contribution on `[1, ∞)` is left to the
+    /// explicit `Complex<f64>` implementation.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::Li5;
+    ///
+    /// let z = 1.0;
+    /// println!("Li5({}) = {}", z, z.li5());
+    /// ```
+    fn li5(&self) -> f64 {
+        let zeta5 = 1.03692775514337_f64;
+        let x = *self;
+
+        // special cases
+        if x == 0.0 {
+            return 0.0;
+        }
+        if x == 1.0 {
+            return zeta5;
+        }
+        if x == -1.0 {
+            return -0.9375*zeta5;
+        }
+
+        let lnx = Float::ln(Float::abs(x)); // = ln|x|, the real part of log(x)
+
+        if x > 0.0 && lnx*lnx < 1.0 {
+            let u  = lnx; // = ln(x)
+            let u4 = u*u*u*u;
+            let a: [f64; 18] = [
+                1.03692775514337, 1.0823232337111381, 0.6010284515797971,
+                0.27415567780803773, 0.0, -0.004166666666666667,
+                -0.00011574074074074075, 0.0, 2.066798941798942e-07, 0.0,
+                -1.0935444136502338e-09, 0.0, 8.698648744945041e-12, 0.0,
+                -8.689958786158883e-14, 0.0, 1.0081254080218813e-15, 0.0,
+            ];
+
+            let mut acc = a[17];
+            for c in a[..17].iter().rev() {
+                acc = fma(u, acc, *c);
+            }
+            // the k = 4 term u⁴(H₄ - log|u|)/4!, with H₄ = 25/12
+            let log_term = u4*(25.0/12.0 - Float::ln(Float::abs(u)))/24.0;
+            return acc + log_term;
+        }
+
+        let bf: [f64; 18] = [
+            1.0, -0.46875, 0.13953189300411523, -0.02863377700617284,
+            0.004031741255144033, -0.0003398501800411523,
+            4.544518462161767e-06, 2.391680804856901e-06,
+            -1.2762692600122746e-07, -3.162898430650593e-08,
+            3.284811844533519e-09, 4.761371399566057e-10,
+            -8.084689817190984e-11, -7.238764858773721e-12,
+            1.9439760115173968e-12, 1.0256978405977236e-13,
+            -4.618055100988483e-14, -1.153585719647058e-15,
+        ];
+
+        let (u, rest, sgn) = if Float::abs(x) <= 1.0 {
+            (-Float::ln(1.0 - x), 0.0, 1.0)
+        } else { // |x| > 1
+            let pi  = core::f64::consts::PI;
+            let pi2 = pi*pi;
+            let pi4 = pi2*pi2;
+            let l   = lnx;
+            let l2  = l*l;
+            let rest = if x > 1.0 {
+                -l2*l2*l/120.0 + pi2*l2*l/18.0 + pi4*l/45.0
+            } else { // x < -1, log(-x) is real
+                -l2*l2*l/120.0 - pi2*l2*l/36.0 - 7.0*pi4*l/360.0
+            };
+            (-Float::ln(1.0 - 1.0/x), rest, 1.0)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + sgn*(u*acc)
+    }
+}
@@ -0,0 +1,205 @@
+use num_traits::Float;
+use num_complex::Complex;
+use li2::{from_f64_complex, fma};
+use cln::CLn;
+use Scalar;
+
+/// Provides the sixth order polylogarithm function `li6()` of a number of
+/// type `T`.
+pub trait Li6<T> {
+    fn li6(&self) -> T;
+}
+
+impl<T: Scalar> Li6<Complex<T>> for Complex<T> {
+    /// Returns the sixth order polylogarithm of a complex number of type
+    /// `Complex<T>`.
+    ///
+    /// # Example:
+    /// ```
+    /// extern crate num_complex;
+    /// extern crate polylog;
+    /// use num_complex::Complex;
+    /// use polylog::Li6;
+    ///
+    /// fn main() {
+    ///     let z = Complex::new(1.0, 1.0);
+    ///     println!("Li6({}) = {}", z, z.li6());
+    /// }
+    /// ```
+    fn li6(&self) -> Complex<T> {
+        let pi    = T::from(core::f64::consts::PI).unwrap();
+        let pi2   = pi*pi;
+        let zeta6 = T::from(1.0173430619844492).unwrap();
+        // a[k] = zeta(6 - k)/k! (the k = 5 entry is the log term, added below)
+        let a: [Complex<T>; 18] = from_f64_complex([
+            1.0173430619844492, 1.03692775514337, 0.5411616168555691,
+            0.20034281719326572, 0.06853891945200943, 0.0,
+            -0.0006944444444444445, -1.6534391534391536e-05, 0.0,
+            2.296443268665491e-08, 0.0, -9.941312851365762e-11, 0.0,
+            6.691268265342339e-13, 0.0, -5.793305857439255e-15, 0.0,
+            5.930149458952243e-17,
+        ]);
+        let bf: [Complex<T>; 18] = from_f64_complex([
+            1.0, -0.484375, 0.15241340877914952, -0.03436555587705761,
+            0.0057174797239369, -0.0006818045374657064,
+            4.9960361948734496e-05, -4.916605119603905e-07,
+            -3.0632975161302163e-07, 1.4414599270849095e-08,
+            3.72724382309241e-09, -3.7300867345487607e-10,
+            -5.1246526816085835e-11, 9.054193095663668e-12,
+            6.738188261551252e-13, -2.1215831150303136e-13,
+            -6.8408811719011696e-15, 4.869117846200558e-15,
+        ]);
+
+        let zero = T::zero();
+        let one  = T::one();
+        let cone = Complex::new(one, zero);
+
+        if self.im == zero {
+            if self.re == zero {
+                return Complex::new(zero, zero);
+            }
+            if self.re == one {
+                return Complex::new(zeta6, zero);
+            }
+            if self.re == -one {
+                return Complex::new(-T::from(0.96875).unwrap()*zeta6, zero);
+            }
+        }
+
+        let nz  = self.norm_sqr();
+        let pz  = self.arg();
+        let lnz = T::from(0.5).unwrap()*nz.ln();
+
+        if lnz*lnz + pz*pz < one { // |log(z)| < 1
+            let u  = Complex::new(lnz, pz); // log(z)
+            let u2 = u*u;
+            let u5 = u2*u2*u;
+
+            let mut acc = a[17];
+            for c in a[..17].iter().rev() {
+                acc = fma(u, acc, *c);
+            }
+            // the k = 5 term u⁵(H₅ - log(-u))/5!, with H₅ = 137/60
+            let log_term = u5 * (Complex::new(T::from(137.0/60.0).unwrap(), zero) - (-u).cln())
+                .unscale(T::from(120).unwrap());
+            return acc + log_term;
+        }
+
+        let (u, rest, sgn) = if nz <= one {
+            (-(cone - self).cln(), Complex::new(zero, zero), one)
+        } else { // nz > 1.0
+            let lmz  = (-self).cln(); // log(-z)
+            let y    = lmz*lmz;
+            // Li₆(z) + Li₆(1/z) = -log⁶(-z)/720 - π² log⁴(-z)/144
+            //                     - 7 π⁴ log²(-z)/720 - 31 π⁶/15120
+            let pi4 = pi2*pi2;
+            let pi6 = pi4*pi2;
+            let inner = Complex::new(-pi2, zero).unscale(T::from(144).unwrap())
+                - y.unscale(T::from(720).unwrap());
+            let mid = Complex::new(-T::from(7).unwrap()*pi4, zero).unscale(T::from(720).unwrap())
+                + y*inner;
+            let rest = Complex::new(-T::from(31).unwrap()*pi6, zero).unscale(T::from(15120).unwrap())
+                + y*mid;
+            (-(cone - cone/self).cln(), rest, -one)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + (u * acc).scale(sgn)
+    }
+}
+
+impl Li6<f64> for f64 {
+    /// Returns the real sixth order polylogarithm of a real number of type
+    /// `f64`.
+    ///
+    /// Like the real `Li4` path, this evaluates `Li6` in pure real
+    /// arithmetic, sparing callers with a real argument the detour through
+    /// `Complex<f64>` and the spurious imaginary noise it leaks for `z < 1`;
+    /// the branch-cut imaginary contribution on `[1, ∞)` is left to the
+    /// explicit `Complex<f64>` implementation.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::Li6;
+    ///
+    /// let z = 1.0;
+    /// println!("Li6({}) = {}", z, z.li6());
+    /// ```
+    fn li6(&self) -> f64 {
+        let zeta6 = 1.0173430619844492_f64;
+        let x = *self;
+
+        // special cases
+        if x == 0.0 {
+            return 0.0;
+        }
+        if x == 1.0 {
+            return zeta6;
+        }
+        if x == -1.0 {
+            return -0.96875*zeta6;
+        }
+
+        let lnx = Float::ln(Float::abs(x)); // = ln|x|, the real part of log(x)
+
+        if x > 0.0 && lnx*lnx < 1.0 {
+            let u  = lnx; // = ln(x)
+            let u2 = u*u;
+            let u5 = u2*u2*u;
+            let a: [f64; 18] = [
+                1.0173430619844492, 1.03692775514337, 0.5411616168555691,
+                0.20034281719326572, 0.06853891945200943, 0.0,
+                -0.0006944444444444445, -1.6534391534391536e-05, 0.0,
+                2.296443268665491e-08, 0.0, -9.941312851365762e-11, 0.0,
+                6.691268265342339e-13, 0.0, -5.793305857439255e-15, 0.0,
+                5.930149458952243e-17,
+            ];
+
+            let mut acc = a[17];
+            for c in a[..17].iter().rev() {
+                acc = fma(u, acc, *c);
+            }
+            // the k = 5 term u⁵(H₅ - log|u|)/5!, with H₅ = 137/60
+            let log_term = u5*(137.0/60.0 - Float::ln(Float::abs(u)))/120.0;
+            return acc + log_term;
+        }
+
+        let bf: [f64; 18] = [
+            1.0, -0.484375, 0.15241340877914952, -0.03436555587705761,
+            0.0057174797239369, -0.0006818045374657064,
+            4.9960361948734496e-05, -4.916605119603905e-07,
+            -3.0632975161302163e-07, 1.4414599270849095e-08,
+            3.72724382309241e-09, -3.7300867345487607e-10,
+            -5.1246526816085835e-11, 9.054193095663668e-12,
+            6.738188261551252e-13, -2.1215831150303136e-13,
+            -6.8408811719011696e-15, 4.869117846200558e-15,
+        ];
+
+        let (u, rest, sgn) = if Float::abs(x) <= 1.0 {
+            (-Float::ln(1.0 - x), 0.0, 1.0)
+        } else { // |x| > 1
+            let pi  = core::f64::consts::PI;
+            let pi2 = pi*pi;
+            let pi4 = pi2*pi2;
+            let pi6 = pi4*pi2;
+            let l   = lnx;
+            let l2  = l*l;
+            let rest = if x > 1.0 {
+                -l2*l2*l2/720.0 + pi2*l2*l2/72.0 + pi4*l2/90.0 + 2.0*pi6/945.0
+            } else { // x < -1, log(-x) is real
+                -l2*l2*l2/720.0 - pi2*l2*l2/144.0 - 7.0*pi4*l2/720.0
+                    - 31.0*pi6/15120.0
+            };
+            (-Float::ln(1.0 - 1.0/x), rest, -1.0)
+        };
+
+        let mut acc = bf[17];
+        for c in bf[..17].iter().rev() {
+            acc = fma(u, acc, *c);
+        }
+        rest + sgn*(u*acc)
+    }
+}
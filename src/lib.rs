@@ -4,11 +4,17 @@
 //! The Polylog package provides Rust implementations of real and
 //! complex polylogarithms.
 //!
+//! The transcendental functions are reached through `num-traits`, so the
+//! crate builds under `#![no_std]`: enable the default `std` feature for a
+//! `std`-backed build, or the `libm` feature to back the floating-point
+//! operations with `libm` when `std` is unavailable (embedded, WASM). This
+//! mirrors how `num-complex` gates its own transcendental functions.
+//!
 //! # Example:
 //! ```
-//! extern crate num;
+//! extern crate num_complex;
 //! extern crate polylog;
-//! use num::complex::Complex;
+//! use num_complex::Complex;
 //! use polylog::{Li2, Li3, Li4, Li5, Li6};
 //!
 //! fn main() {
@@ -23,8 +29,29 @@
 //! }
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// The modules below use `core::` paths, which resolve in both the `std`
+// and the `no_std` build; the `std` build also declares `core` here.
+#[cfg(feature = "std")]
+extern crate core;
+extern crate num_complex;
+extern crate num_traits;
 
-extern crate num;
+use num_traits::Float;
+use num_traits::MulAdd;
+
+/// Marker for the real floating-point types the polylogarithms are
+/// implemented for.
+///
+/// The generic scalar impl (`impl Li2<T> for T`) is sealed behind this
+/// crate-local trait so it does not overlap the `impl Li2<Complex<T>> for
+/// Complex<T>` counterpart: coherence cannot otherwise rule out a future
+/// `Complex<T>: Float`, but it can see that no `Complex<T>` implements this
+/// local trait. The real `Li3` to `Li6` impls are for `f64` only.
+pub(crate) trait Scalar: Float + MulAdd<Output = Self> {}
+impl Scalar for f32 {}
+impl Scalar for f64 {}
 
 mod cln;
 mod li2;
@@ -33,6 +60,28 @@ mod li4;
 mod li5;
 mod li6;
 
+/// Selects the side of the polylogarithm branch cut along the real axis
+/// for `z >= 1`.
+///
+/// Following the convention `num-complex` documents for `cbrt` (a branch
+/// stated as "continuous from above" with a fixed `arg` range), this lets a
+/// caller fix the `+iε`/`−iε` prescription when evaluating `Li_n` on the cut
+/// instead of special-casing it by hand. On the cut the two sheets are
+/// complex conjugates of each other.
+///
+/// The bare `li2()`/`li4()` entry points return the [`BranchSide::Below`]
+/// (`−iε`) value, so `li4(x)` for real `x > 1` carries a negative
+/// imaginary part; [`BranchSide::Above`] flips its sign.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BranchSide {
+    /// Continuous from above the cut (the `+iε` prescription, `Im z → 0⁺`),
+    /// giving `Im Li_n(x) = +π ln^{n-1}(x)/(n-1)!` for real `x > 1`.
+    Above,
+    /// Continuous from below the cut (the `−iε` prescription, `Im z → 0⁻`),
+    /// giving `Im Li_n(x) = −π ln^{n-1}(x)/(n-1)!` for real `x > 1`.
+    Below,
+}
+
 pub use self::li2::Li2;
 pub use self::li3::Li3;
 pub use self::li4::Li4;
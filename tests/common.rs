@@ -0,0 +1,41 @@
+extern crate num;
+extern crate num_complex;
+
+use num_complex::Complex;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+/// Asserts that two complex numbers agree to within an absolute tolerance
+/// `eps` in both components.
+pub fn assert_eq_complex(a: Complex<f64>, b: Complex<f64>, eps: f64) {
+    assert!((a.re - b.re).abs() < eps, "re: {} != {} (eps {})", a.re, b.re, eps);
+    assert!((a.im - b.im).abs() < eps, "im: {} != {} (eps {})", a.im, b.im, eps);
+}
+
+/// Reads a table of reference values from `tests/data/<name>`. Each line
+/// holds four whitespace-separated floats: the real and imaginary parts of
+/// the argument followed by those of the expected polylogarithm.
+pub fn read_data_file(name: &str) -> std::io::Result<Vec<(Complex<f64>, Complex<f64>)>> {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests");
+    path.push("data");
+    path.push(name);
+
+    let file = File::open(path)?;
+    let mut values = Vec::new();
+
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        let cols: Vec<f64> = line
+            .split_whitespace()
+            .map(|c| c.parse().unwrap())
+            .collect();
+        values.push((
+            Complex::new(cols[0], cols[1]),
+            Complex::new(cols[2], cols[3]),
+        ));
+    }
+
+    Ok(values)
+}
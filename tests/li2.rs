@@ -0,0 +1,33 @@
+extern crate polylog;
+extern crate num_complex;
+use num_complex::Complex;
+use polylog::Li2;
+mod common;
+use common::assert_eq_complex;
+
+
+#[test]
+fn special_values() {
+    let pi = std::f64::consts::PI;
+
+    assert!((1.0_f64.li2() - pi*pi/6.).abs() < 1e-15);
+    assert!(((-1.0_f64).li2() + pi*pi/12.).abs() < 1e-15);
+    assert!(0.0_f64.li2().abs() < 1e-15);
+
+    let eps = 1e-15;
+    assert_eq_complex(Complex::new(1., 0.).li2(),
+                      Complex::new(pi*pi/6., 0.), eps);
+    assert_eq_complex(Complex::new(-1., 0.).li2(),
+                      Complex::new(-pi*pi/12., 0.), eps);
+}
+
+
+#[test]
+fn test_values() {
+    let eps = 1e-14;
+    let values = common::read_data_file("Li2.txt").unwrap();
+
+    for &(v, li2) in values.iter() {
+        assert_eq_complex(v.li2(), li2, eps);
+    }
+}
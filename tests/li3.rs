@@ -0,0 +1,44 @@
+extern crate polylog;
+extern crate num_complex;
+extern crate num;
+use num_complex::Complex;
+use polylog::Li3;
+mod common;
+use common::assert_eq_complex;
+
+
+#[test]
+fn special_values() {
+    use num::Zero;
+    let eps = 1e-14;
+    let zero = Complex::zero();
+    assert_eq_complex(zero.li3(), zero, eps);
+}
+
+
+#[test]
+fn test_values() {
+    let eps = 1e-13;
+    let values = common::read_data_file("Li3.txt").unwrap();
+
+    for &(v, li3) in values.iter() {
+        assert_eq_complex(v.li3(), li3, eps);
+    }
+}
+
+
+// Guards the fused-multiply-add Horner rewrite: run with `cargo test` to
+// exercise the naive `a*b + c` form and with `cargo test --features fma`
+// to exercise `a.mul_add(b, c)`; the real evaluation must track the real
+// part of the complex one to the bound below in either form.
+#[test]
+fn fma_accuracy() {
+    let eps = 1e-13;
+    let xs = [-5.0, -2.0, -0.9, -0.5, 0.25, 0.5, 0.8, 0.95, 1.2, 2.0, 5.0];
+
+    for &x in xs.iter() {
+        let re = Complex::new(x, 0.).li3().re;
+        assert!((x.li3() - re).abs() < eps,
+                "li3({}) = {}, Re complex = {}", x, x.li3(), re);
+    }
+}
@@ -1,6 +1,7 @@
 extern crate polylog;
+extern crate num_complex;
 extern crate num;
-use num::complex::Complex;
+use num_complex::Complex;
 use polylog::Li4;
 mod common;
 use common::assert_eq_complex;
@@ -20,6 +21,12 @@ fn special_values() {
                       Complex::new(-7./8.*z4, 0.), eps);
     assert_eq_complex(Complex::new(0.5, 0.).li4(),
                       Complex::new(0.5174790616738994, 0.), eps);
+
+    // the real path agrees with the real part of the complex one
+    assert!((1.0_f64.li4() - z4).abs() < eps);
+    assert!(((-1.0_f64).li4() + 7./8.*z4).abs() < eps);
+    assert!((0.5_f64.li4() - 0.5174790616738994).abs() < eps);
+    assert!(0.0_f64.li4().abs() < eps);
 }
 
 
@@ -32,3 +39,22 @@ fn test_values() {
         assert_eq_complex(v.li4(), li4, eps);
     }
 }
+
+
+// Guards the fused-multiply-add Horner/Clenshaw rewrite: run with
+// `cargo test` to exercise the naive `a*b + c` form and with
+// `cargo test --features fma` to exercise `a.mul_add(b, c)`; the error
+// bound below must hold either way, so an accuracy regression in either
+// form fails the build.
+#[test]
+fn fma_accuracy() {
+    let eps = 1e-13;
+    let xs = [-5.0, -2.0, -0.9, -0.5, 0.25, 0.5, 0.8, 0.95, 1.2, 2.0, 5.0];
+
+    for &x in xs.iter() {
+        // the real evaluation must track the real part of the complex one
+        let re = Complex::new(x, 0.).li4().re;
+        assert!((x.li4() - re).abs() < eps,
+                "li4({}) = {}, Re complex = {}", x, x.li4(), re);
+    }
+}